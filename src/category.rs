@@ -0,0 +1,28 @@
+/// Categories that can be added to an [`super::Intent`] to give additional context about the
+/// action it requests, via [`super::IntentBuilder::add_category`].
+///
+/// <https://developer.android.com/reference/android/content/Intent#addCategory(java.lang.String)>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Category {
+    /// <https://developer.android.com/reference/android/content/Intent#CATEGORY_DEFAULT>
+    Default,
+    /// <https://developer.android.com/reference/android/content/Intent#CATEGORY_BROWSABLE>
+    Browsable,
+    /// <https://developer.android.com/reference/android/content/Intent#CATEGORY_LAUNCHER>
+    Launcher,
+    /// <https://developer.android.com/reference/android/content/Intent#CATEGORY_OPENABLE>
+    Openable,
+}
+
+impl Category {
+    /// The name of the static `String` field on `android.content.Intent` backing this category.
+    pub(crate) fn field_name(self) -> &'static str {
+        match self {
+            Category::Default => "CATEGORY_DEFAULT",
+            Category::Browsable => "CATEGORY_BROWSABLE",
+            Category::Launcher => "CATEGORY_LAUNCHER",
+            Category::Openable => "CATEGORY_OPENABLE",
+        }
+    }
+}
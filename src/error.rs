@@ -0,0 +1,97 @@
+use jni::JNIEnv;
+
+/// Errors that can occur while building or launching an [`crate::Intent`].
+///
+/// Terminal calls (`start_activity`, `start_service`, ...) check for a pending Java exception
+/// after the underlying JNI call and translate well-known Android exceptions into dedicated
+/// variants, so callers can distinguish "no app can handle this intent" from a genuine JNI
+/// failure.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// `android.content.ActivityNotFoundException`: no activity/service/broadcast receiver was
+    /// found to handle the intent.
+    ActivityNotFound(String),
+    /// `java.lang.SecurityException`: the caller is missing a permission required by the target
+    /// component.
+    Security(String),
+    /// Some other Java exception was thrown.
+    Java { class_name: String, message: String },
+    /// A JNI-level failure unrelated to a thrown Java exception.
+    Jni(jni::errors::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ActivityNotFound(message) => {
+                write!(f, "no activity found to handle intent: {message}")
+            }
+            Error::Security(message) => write!(f, "security exception: {message}"),
+            Error::Java { class_name, message } => write!(f, "{class_name}: {message}"),
+            Error::Jni(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Jni(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<jni::errors::Error> for Error {
+    fn from(error: jni::errors::Error) -> Self {
+        Error::Jni(error)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Run `f`, then check for a pending Java exception and translate it into a typed [`Error`] if
+/// one was thrown, clearing it so the JNI env is usable again.
+pub(crate) fn catch<T>(
+    env: &mut JNIEnv<'_>,
+    f: impl FnOnce(&mut JNIEnv<'_>) -> jni::errors::Result<T>,
+) -> Result<T> {
+    let result = f(env);
+
+    if env.exception_check().unwrap_or(false) {
+        let throwable = env
+            .exception_occurred()
+            .expect("exception_check returned true but no exception is pending");
+        env.exception_clear().ok();
+
+        let class_name = (|| -> jni::errors::Result<String> {
+            let class = env.get_object_class(&throwable)?;
+            let name = env
+                .call_method(class, "getName", "()Ljava/lang/String;", &[])?
+                .l()?;
+            let name: jni::objects::JString = name.into();
+            let name = env.get_string(&name)?;
+            Ok(name.into())
+        })()
+        .unwrap_or_else(|_| "<unknown exception class>".to_string());
+
+        let message = (|| -> jni::errors::Result<String> {
+            let message = env
+                .call_method(&throwable, "getMessage", "()Ljava/lang/String;", &[])?
+                .l()?;
+            let message: jni::objects::JString = message.into();
+            let message = env.get_string(&message)?;
+            Ok(message.into())
+        })()
+        .unwrap_or_default();
+
+        return Err(match class_name.as_str() {
+            "android.content.ActivityNotFoundException" => Error::ActivityNotFound(message),
+            "java.lang.SecurityException" => Error::Security(message),
+            _ => Error::Java { class_name, message },
+        });
+    }
+
+    Ok(result?)
+}
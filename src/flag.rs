@@ -0,0 +1,34 @@
+/// Flags that can be set on an [`super::Intent`] to control how it is handled, via
+/// [`super::IntentBuilder::add_flags`] / [`super::IntentBuilder::set_flags`].
+///
+/// <https://developer.android.com/reference/android/content/Intent#setFlags(int)>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Flag {
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_ACTIVITY_NEW_TASK>
+    ActivityNewTask,
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_ACTIVITY_CLEAR_TOP>
+    ActivityClearTop,
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_ACTIVITY_SINGLE_TOP>
+    ActivitySingleTop,
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_ACTIVITY_NO_HISTORY>
+    ActivityNoHistory,
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_GRANT_READ_URI_PERMISSION>
+    GrantReadUriPermission,
+    /// <https://developer.android.com/reference/android/content/Intent#FLAG_GRANT_WRITE_URI_PERMISSION>
+    GrantWriteUriPermission,
+}
+
+impl Flag {
+    /// The name of the static `int` field on `android.content.Intent` backing this flag.
+    pub(crate) fn field_name(self) -> &'static str {
+        match self {
+            Flag::ActivityNewTask => "FLAG_ACTIVITY_NEW_TASK",
+            Flag::ActivityClearTop => "FLAG_ACTIVITY_CLEAR_TOP",
+            Flag::ActivitySingleTop => "FLAG_ACTIVITY_SINGLE_TOP",
+            Flag::ActivityNoHistory => "FLAG_ACTIVITY_NO_HISTORY",
+            Flag::GrantReadUriPermission => "FLAG_GRANT_READ_URI_PERMISSION",
+            Flag::GrantWriteUriPermission => "FLAG_GRANT_WRITE_URI_PERMISSION",
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use jni::{
     errors::Result,
-    objects::{JObject, JString, JValue},
+    objects::{JIntArray, JObject, JObjectArray, JString, JValue, JValueOwned},
     JNIEnv,
 };
 
+use crate::{Category, Flag};
+
 /// A messaging object you can use to request an action from another android app component.
 #[must_use]
 pub struct Intent<'vm, 'env> {
@@ -55,6 +57,109 @@ impl<'vm, 'env> Intent<'vm, 'env> {
         let extra = self.env.get_string(&extra)?;
         Ok(extra.into())
     }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getIntExtra(java.lang.String,%20int)>
+    pub fn int_extra(&mut self, name: &str, default_value: i32) -> Result<i32> {
+        let name = self.env.new_string(name)?;
+
+        self.env
+            .call_method(
+                &self.object,
+                "getIntExtra",
+                "(Ljava/lang/String;I)I",
+                &[JValue::Object(&name), JValue::Int(default_value)],
+            )?
+            .i()
+    }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getBooleanExtra(java.lang.String,%20boolean)>
+    pub fn bool_extra(&mut self, name: &str, default_value: bool) -> Result<bool> {
+        let name = self.env.new_string(name)?;
+
+        self.env
+            .call_method(
+                &self.object,
+                "getBooleanExtra",
+                "(Ljava/lang/String;Z)Z",
+                &[JValue::Object(&name), JValue::Bool(default_value as u8)],
+            )?
+            .z()
+    }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getLongExtra(java.lang.String,%20long)>
+    pub fn long_extra(&mut self, name: &str, default_value: i64) -> Result<i64> {
+        let name = self.env.new_string(name)?;
+
+        self.env
+            .call_method(
+                &self.object,
+                "getLongExtra",
+                "(Ljava/lang/String;J)J",
+                &[JValue::Object(&name), JValue::Long(default_value)],
+            )?
+            .j()
+    }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getFloatExtra(java.lang.String,%20float)>
+    pub fn float_extra(&mut self, name: &str, default_value: f32) -> Result<f32> {
+        let name = self.env.new_string(name)?;
+
+        self.env
+            .call_method(
+                &self.object,
+                "getFloatExtra",
+                "(Ljava/lang/String;F)F",
+                &[JValue::Object(&name), JValue::Float(default_value)],
+            )?
+            .f()
+    }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getStringArrayExtra(java.lang.String)>
+    pub fn string_array_extra(&mut self, name: &str) -> Result<Vec<String>> {
+        let name = self.env.new_string(name)?;
+
+        let array: JObjectArray = self
+            .env
+            .call_method(
+                &self.object,
+                "getStringArrayExtra",
+                "(Ljava/lang/String;)[Ljava/lang/String;",
+                &[JValue::Object(&name)],
+            )?
+            .l()?
+            .into();
+
+        let len = self.env.get_array_length(&array)?;
+        let mut result = Vec::with_capacity(len as usize);
+        for i in 0..len {
+            let element: JString = self.env.get_object_array_element(&array, i)?.into();
+            result.push(self.env.get_string(&element)?.into());
+        }
+
+        Ok(result)
+    }
+
+    /// <https://developer.android.com/reference/android/content/Intent#getIntArrayExtra(java.lang.String)>
+    pub fn int_array_extra(&mut self, name: &str) -> Result<Vec<i32>> {
+        let name = self.env.new_string(name)?;
+
+        let array: JIntArray = self
+            .env
+            .call_method(
+                &self.object,
+                "getIntArrayExtra",
+                "(Ljava/lang/String;)[I",
+                &[JValue::Object(&name)],
+            )?
+            .l()?
+            .into();
+
+        let len = self.env.get_array_length(&array)?;
+        let mut result = vec![0; len as usize];
+        self.env.get_int_array_region(&array, 0, &mut result)?;
+
+        Ok(result)
+    }
 }
 
 /// A messaging object you can use to request an action from another Android app component.
@@ -178,6 +283,125 @@ impl<'vm, 'env> IntentBuilder<'vm, 'env> {
         })
     }
 
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20int)>
+    pub fn with_int_extra(self, key: impl AsRef<str>, value: i32) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;I)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Int(value)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20boolean)>
+    pub fn with_bool_extra(self, key: impl AsRef<str>, value: bool) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;Z)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Bool(value as u8)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20long)>
+    pub fn with_long_extra(self, key: impl AsRef<str>, value: i64) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;J)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Long(value)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20float)>
+    pub fn with_float_extra(self, key: impl AsRef<str>, value: f32) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;F)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Float(value)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20java.lang.String[])>
+    pub fn with_string_array_extra(
+        self,
+        key: impl AsRef<str>,
+        values: &[impl AsRef<str>],
+    ) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            let string_class = inner.env.find_class("java/lang/String")?;
+            let array =
+                inner
+                    .env
+                    .new_object_array(values.len() as i32, &string_class, JObject::null())?;
+            for (i, value) in values.iter().enumerate() {
+                let value = inner.env.new_string(value)?;
+                inner.env.set_object_array_element(&array, i as i32, value)?;
+            }
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;[Ljava/lang/String;)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Object(&array)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add extended data to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#putExtra(java.lang.String,%20int[])>
+    pub fn with_int_array_extra(self, key: impl AsRef<str>, values: &[i32]) -> Self {
+        self.and_then(|inner| {
+            let key = inner.env.new_string(key)?;
+
+            let array = inner.env.new_int_array(values.len() as i32)?;
+            inner.env.set_int_array_region(&array, 0, values)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;[I)Landroid/content/Intent;",
+                &[JValue::Object(&key), JValue::Object(&array)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
     /// Builds a new [`super::Action::Chooser`] Intent that wraps the given target intent.
     /// ```no_run
     /// use android_intent::{Action, IntentBuilder};
@@ -212,6 +436,69 @@ impl<'vm, 'env> IntentBuilder<'vm, 'env> {
         })
     }
 
+    /// Like [`Self::into_chooser_with_title`], but prepends custom-labeled targets ahead of the
+    /// system-resolved share targets, via `Intent.EXTRA_INITIAL_INTENTS`.
+    ///
+    /// Each `(IntentBuilder, label)` pair becomes an `android.content.pm.LabeledIntent` attributed
+    /// to this app's own package.
+    pub fn into_chooser_with_initial_intents(
+        self,
+        title: Option<impl AsRef<str>>,
+        initial_intents: Vec<(IntentBuilder<'vm, 'env>, String)>,
+    ) -> Self {
+        self.into_chooser_with_title(title).and_then(|inner| {
+            let cx = ndk_context::android_context();
+            let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+            let package_name = inner
+                .env
+                .call_method(&context, "getPackageName", "()Ljava/lang/String;", &[])?
+                .l()?;
+
+            let labeled_intent_class = inner.env.find_class("android/content/pm/LabeledIntent")?;
+            let array = inner.env.new_object_array(
+                initial_intents.len() as i32,
+                &labeled_intent_class,
+                JObject::null(),
+            )?;
+
+            for (i, (initial_intent, label)) in initial_intents.into_iter().enumerate() {
+                let initial_intent = initial_intent.build()?;
+                let label = inner.env.new_string(label)?;
+
+                let labeled_intent = inner.env.new_object(
+                    &labeled_intent_class,
+                    "(Landroid/content/Intent;Ljava/lang/String;Ljava/lang/CharSequence;I)V",
+                    &[
+                        JValue::Object(&initial_intent),
+                        JValue::Object(&package_name),
+                        JValue::Object(&label),
+                        JValue::Int(0),
+                    ],
+                )?;
+
+                inner
+                    .env
+                    .set_object_array_element(&array, i as i32, labeled_intent)?;
+            }
+
+            let intent_class = inner.env.find_class("android/content/Intent")?;
+            let extra_initial_intents = inner.env.get_static_field(
+                &intent_class,
+                "EXTRA_INITIAL_INTENTS",
+                "Ljava/lang/String;",
+            )?;
+
+            inner.env.call_method(
+                &inner.object,
+                "putExtra",
+                "(Ljava/lang/String;[Landroid/os/Parcelable;)Landroid/content/Intent;",
+                &[extra_initial_intents.borrow(), JValue::Object(&array)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
     /// Set an explicit MIME data type.
     /// ```no_run
     /// use android_intent::{Action, IntentBuilder};
@@ -236,12 +523,70 @@ impl<'vm, 'env> IntentBuilder<'vm, 'env> {
         })
     }
 
-    pub fn start_activity(self) -> Result<()> {
+    /// Add additional flags to the intent (or with existing flags value).
+    /// <https://developer.android.com/reference/android/content/Intent#addFlags(int)>
+    pub fn add_flags(self, flags: &[Flag]) -> Self {
+        self.and_then(|inner| {
+            let flags = combine_flags(inner.env, flags)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "addFlags",
+                "(I)Landroid/content/Intent;",
+                &[JValue::Int(flags)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Set the flags on the intent, replacing any that were previously set.
+    /// <https://developer.android.com/reference/android/content/Intent#setFlags(int)>
+    pub fn set_flags(self, flags: &[Flag]) -> Self {
+        self.and_then(|inner| {
+            let flags = combine_flags(inner.env, flags)?;
+
+            inner.env.call_method(
+                &inner.object,
+                "setFlags",
+                "(I)Landroid/content/Intent;",
+                &[JValue::Int(flags)],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// Add a category to the intent.
+    /// <https://developer.android.com/reference/android/content/Intent#addCategory(java.lang.String)>
+    pub fn add_category(self, category: Category) -> Self {
+        self.and_then(|inner| {
+            let intent_class = inner.env.find_class("android/content/Intent")?;
+            let category_value = inner
+                .env
+                .get_static_field(&intent_class, category.field_name(), "Ljava/lang/String;")?;
+
+            inner.env.call_method(
+                &inner.object,
+                "addCategory",
+                "(Ljava/lang/String;)Landroid/content/Intent;",
+                &[category_value.borrow()],
+            )?;
+
+            Ok(inner)
+        })
+    }
+
+    /// <https://developer.android.com/reference/android/content/Context#startActivity(android.content.Intent)>
+    ///
+    /// Returns [`crate::Error::ActivityNotFound`] if no activity can handle this intent.
+    pub fn start_activity(self) -> crate::error::Result<()> {
         let cx = ndk_context::android_context();
         let activity = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+        let inner = self.inner?;
 
-        self.inner.and_then(|inner| {
-            inner.env.call_method(
+        crate::error::catch(inner.env, |env| {
+            env.call_method(
                 activity,
                 "startActivity",
                 "(Landroid/content/Intent;)V",
@@ -252,8 +597,142 @@ impl<'vm, 'env> IntentBuilder<'vm, 'env> {
         })
     }
 
+    /// Start the activity described by this intent, expecting a result back via
+    /// `Activity#onActivityResult(int, int, Intent)` tagged with `request_code`.
+    ///
+    /// The `Intent` delivered to `onActivityResult` can be turned back into an [`Intent`] with
+    /// [`Intent::from_object`].
+    ///
+    /// <https://developer.android.com/reference/android/app/Activity#startActivityForResult(android.content.Intent,%20int)>
+    pub fn start_activity_for_result(self, request_code: i32) -> crate::error::Result<()> {
+        let cx = ndk_context::android_context();
+        let activity = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+        let inner = self.inner?;
+
+        crate::error::catch(inner.env, |env| {
+            env.call_method(
+                activity,
+                "startActivityForResult",
+                "(Landroid/content/Intent;I)V",
+                &[JValue::Object(&inner.object), JValue::Int(request_code)],
+            )?;
+
+            Ok(())
+        })
+    }
+
+    /// Start the `Service` described by this intent.
+    ///
+    /// Returns `Ok(None)` if no such service exists, as `Context.startService` signals that case
+    /// by returning `null` rather than throwing.
+    ///
+    /// <https://developer.android.com/reference/android/content/Context#startService(android.content.Intent)>
+    pub fn start_service(self) -> crate::error::Result<Option<String>> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+        let inner = self.inner?;
+
+        crate::error::catch(inner.env, |env| {
+            let component_name = env.call_method(
+                &context,
+                "startService",
+                "(Landroid/content/Intent;)Landroid/content/ComponentName;",
+                &[JValue::Object(&inner.object)],
+            )?;
+
+            component_name_to_string(env, component_name)
+        })
+    }
+
+    /// Start the `Service` described by this intent as a foreground service.
+    ///
+    /// Returns `Ok(None)` if no such service exists, as `Context.startForegroundService` signals
+    /// that case by returning `null` rather than throwing.
+    ///
+    /// <https://developer.android.com/reference/android/content/Context#startForegroundService(android.content.Intent)>
+    pub fn start_foreground_service(self) -> crate::error::Result<Option<String>> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+        let inner = self.inner?;
+
+        crate::error::catch(inner.env, |env| {
+            let component_name = env.call_method(
+                &context,
+                "startForegroundService",
+                "(Landroid/content/Intent;)Landroid/content/ComponentName;",
+                &[JValue::Object(&inner.object)],
+            )?;
+
+            component_name_to_string(env, component_name)
+        })
+    }
+
+    /// Stop the `Service` described by this intent.
+    /// <https://developer.android.com/reference/android/content/Context#stopService(android.content.Intent)>
+    pub fn stop_service(self) -> crate::error::Result<bool> {
+        let cx = ndk_context::android_context();
+        let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+        let inner = self.inner?;
+
+        crate::error::catch(inner.env, |env| {
+            env.call_method(
+                &context,
+                "stopService",
+                "(Landroid/content/Intent;)Z",
+                &[JValue::Object(&inner.object)],
+            )?
+            .z()
+        })
+    }
+
     fn and_then(mut self, f: impl FnOnce(Intent<'vm, 'env>) -> Result<Intent<'vm, 'env>>) -> Self {
         self.inner = self.inner.and_then(f);
         self
     }
+
+    /// Consume the builder, handing back the underlying JNI object for use by other subsystems
+    /// (such as [`crate::TaskStack`]) that need to embed this intent in further JNI calls.
+    pub(crate) fn build(self) -> Result<JObject<'env>> {
+        self.inner.map(|intent| intent.object)
+    }
+}
+
+/// Convert an `android.content.ComponentName` returned from a `startService`-family call into
+/// its flattened `package/class` string, as produced by `ComponentName.flattenToString()`.
+///
+/// `Context.startService`/`startForegroundService` return `null` (rather than throwing) when no
+/// such service exists, so this returns `Ok(None)` in that case instead of tripping the non-null
+/// check in [`JNIEnv::call_method`].
+fn component_name_to_string(
+    env: &mut JNIEnv<'_>,
+    component_name: JValueOwned<'_>,
+) -> Result<Option<String>> {
+    let component_name = component_name.l()?;
+    if component_name.is_null() {
+        return Ok(None);
+    }
+
+    let name = env
+        .call_method(
+            component_name,
+            "flattenToString",
+            "()Ljava/lang/String;",
+            &[],
+        )?
+        .l()?
+        .into();
+    let name = env.get_string(&name)?;
+    Ok(Some(name.into()))
+}
+
+/// OR together the `int` values of the given [`Flag`]s' static fields on `Intent`.
+fn combine_flags(env: &mut JNIEnv<'_>, flags: &[Flag]) -> Result<i32> {
+    let intent_class = env.find_class("android/content/Intent")?;
+
+    flags.iter().try_fold(0, |acc, flag| {
+        let value = env
+            .get_static_field(&intent_class, flag.field_name(), "I")?
+            .i()?;
+        Ok(acc | value)
+    })
 }
@@ -1,11 +1,26 @@
 mod action;
 pub use action::Action;
 
+mod category;
+pub use category::Category;
+
+mod error;
+pub use error::Error;
+
 mod extra;
 pub use extra::Extra;
 
+mod flag;
+pub use flag::Flag;
+
 mod intent;
 pub use intent::Intent;
+
+mod router;
+pub use router::Router;
+
+mod task_stack;
+pub use task_stack::TaskStack;
 use jni::{JNIEnv, JavaVM};
 
 /// Run 'f' with the current [`JNIEnv`] from [`ndk_context`].
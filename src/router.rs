@@ -0,0 +1,137 @@
+use regex::{Captures, Regex};
+
+use crate::Intent;
+
+/// A handler invoked when a [`Router`] route matches an incoming [`Intent`]'s data URI.
+pub type Handler<'vm, 'env> = Box<dyn FnMut(&Captures, &mut Intent<'vm, 'env>) + 'vm>;
+
+/// Dispatches an incoming `VIEW` [`Intent`] to a handler based on the path of its data URI,
+/// modeled on the path-to-handler routers used for deep-linking in large apps.
+///
+/// Routes are tried in the order they were registered and the first pattern that matches the
+/// full path wins.
+///
+/// ```no_run
+/// use android_intent::{Intent, Router};
+///
+/// let mut router = Router::new()
+///     .route(r"^/users/(\d+)$", |captures, _intent| {
+///         let _user_id = &captures[1];
+///     })
+///     .route(r"^/invite/(?P<code>\w+)$", |captures, _intent| {
+///         let _code = &captures["code"];
+///     });
+/// ```
+pub struct Router<'vm, 'env> {
+    routes: Vec<(Regex, Handler<'vm, 'env>)>,
+}
+
+impl<'vm, 'env> Router<'vm, 'env> {
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    /// Register a handler for Intents whose data URI path fully matches `pattern`.
+    ///
+    /// Routes are checked in the order they are added, so earlier routes take precedence over
+    /// later, more general ones.
+    pub fn route(
+        mut self,
+        pattern: &str,
+        handler: impl FnMut(&Captures, &mut Intent<'vm, 'env>) + 'vm,
+    ) -> Self {
+        let regex = Regex::new(&format!("^(?:{pattern})$")).expect("invalid route pattern");
+        self.routes.push((regex, Box::new(handler)));
+        self
+    }
+
+    /// Match `intent`'s data URI path against the registered routes and invoke the first
+    /// handler whose pattern fully matches, passing it the capture groups and the live intent.
+    ///
+    /// Returns whether a route matched. A missing or unparseable data string is treated as "no
+    /// match" rather than an error.
+    pub fn dispatch(&mut self, intent: &mut Intent<'vm, 'env>) -> bool {
+        let Ok(data_string) = intent.data_string() else {
+            return false;
+        };
+        let Some(path) = path_of(&data_string) else {
+            return false;
+        };
+
+        for (regex, handler) in &mut self.routes {
+            let Some(captures) = full_match(regex, path) else {
+                continue;
+            };
+            handler(&captures, intent);
+            return true;
+        }
+
+        false
+    }
+}
+
+impl Default for Router<'_, '_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Match `regex` against `path`, returning the captures only if the match spans the full path
+/// rather than just a substring of it.
+fn full_match<'a>(regex: &Regex, path: &'a str) -> Option<Captures<'a>> {
+    let captures = regex.captures(path)?;
+    let whole_match = captures.get(0)?;
+    (whole_match.start() == 0 && whole_match.end() == path.len()).then_some(captures)
+}
+
+/// Split a URI's `scheme://host` prefix off, returning the remaining path (including its
+/// leading `/`, but excluding any trailing `?query` or `#fragment`), or `None` if the string has
+/// no authority to split off.
+fn path_of(data_string: &str) -> Option<&str> {
+    let (_, after_scheme) = data_string.split_once("://")?;
+    let path_start = after_scheme.find('/').unwrap_or(after_scheme.len());
+    let path = &after_scheme[path_start..];
+    let path_end = path.find(['?', '#']).unwrap_or(path.len());
+    Some(&path[..path_end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_of_strips_scheme_and_host() {
+        assert_eq!(path_of("myapp://host/users/42"), Some("/users/42"));
+    }
+
+    #[test]
+    fn path_of_strips_query_string() {
+        assert_eq!(
+            path_of("myapp://host/users/42?ref=abc"),
+            Some("/users/42")
+        );
+    }
+
+    #[test]
+    fn path_of_strips_fragment() {
+        assert_eq!(path_of("myapp://host/users/42#section"), Some("/users/42"));
+    }
+
+    #[test]
+    fn path_of_rejects_uri_without_authority() {
+        assert_eq!(path_of("not-a-uri"), None);
+    }
+
+    #[test]
+    fn full_match_matches_whole_path() {
+        let regex = Regex::new(r"^/users/(\d+)$").unwrap();
+        let captures = full_match(&regex, "/users/42").unwrap();
+        assert_eq!(&captures[1], "42");
+    }
+
+    #[test]
+    fn full_match_rejects_partial_match() {
+        let regex = Regex::new(r"/users/(\d+)").unwrap();
+        assert!(full_match(&regex, "/users/42/edit").is_none());
+    }
+}
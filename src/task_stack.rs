@@ -0,0 +1,107 @@
+use jni::{
+    errors::Result,
+    objects::{JObject, JValue},
+    JNIEnv,
+};
+
+use crate::intent::IntentBuilder;
+
+/// Synthesizes a proper navigation back stack for an activity launched out of context (e.g. from
+/// a notification or a deep link), wrapping `android.app.TaskStackBuilder`.
+///
+/// ```no_run
+/// use android_intent::{Action, IntentBuilder, TaskStack};
+///
+/// # android_intent::with_current_env(|env| {
+/// // The task stack and the intent each hold their own `&mut JNIEnv`.
+/// let mut intent_env = unsafe { env.unsafe_clone() };
+/// TaskStack::create(env)
+///     .add_next_intent_with_parent_stack(IntentBuilder::new(&mut intent_env, Action::View))
+///     .start_activities()
+///     .unwrap();
+/// # })
+/// ```
+#[must_use]
+pub struct TaskStack<'vm, 'env> {
+    inner: Result<TaskStackInner<'vm, 'env>>,
+}
+
+struct TaskStackInner<'vm, 'env> {
+    env: &'vm mut JNIEnv<'env>,
+    object: JObject<'env>,
+}
+
+impl<'vm, 'env> TaskStack<'vm, 'env> {
+    /// <https://developer.android.com/reference/android/app/TaskStackBuilder#create(android.content.Context)>
+    pub fn create(env: &'vm mut JNIEnv<'env>) -> Self {
+        Self::from_fn(env, |env| {
+            let cx = ndk_context::android_context();
+            let context = unsafe { JObject::from_raw(cx.context() as jni::sys::jobject) };
+
+            let task_stack_builder_class = env.find_class("android/app/TaskStackBuilder")?;
+            let object = env
+                .call_static_method(
+                    task_stack_builder_class,
+                    "create",
+                    "(Landroid/content/Context;)Landroid/app/TaskStackBuilder;",
+                    &[JValue::Object(&context)],
+                )?
+                .l()?;
+
+            Ok(object)
+        })
+    }
+
+    fn from_fn(
+        env: &'vm mut JNIEnv<'env>,
+        f: impl FnOnce(&mut JNIEnv<'env>) -> Result<JObject<'env>>,
+    ) -> Self {
+        let object = f(env);
+        let inner = object.map(|object| TaskStackInner { env, object });
+        Self { inner }
+    }
+
+    /// Add an intent to the back stack, on top of the stack synthesized from its target
+    /// activity's `<meta-data>` parent hierarchy.
+    ///
+    /// <https://developer.android.com/reference/android/app/TaskStackBuilder#addNextIntentWithParentStack(android.content.Intent)>
+    pub fn add_next_intent_with_parent_stack(self, intent: IntentBuilder<'vm, 'env>) -> Self {
+        self.and_then(intent, "addNextIntentWithParentStack")
+    }
+
+    /// Add an intent to the back stack.
+    ///
+    /// <https://developer.android.com/reference/android/app/TaskStackBuilder#addNextIntent(android.content.Intent)>
+    pub fn add_next_intent(self, intent: IntentBuilder<'vm, 'env>) -> Self {
+        self.and_then(intent, "addNextIntent")
+    }
+
+    fn and_then(mut self, intent: IntentBuilder<'vm, 'env>, method_name: &str) -> Self {
+        self.inner = self.inner.and_then(|inner| {
+            let intent_object = intent.build()?;
+
+            inner.env.call_method(
+                &inner.object,
+                method_name,
+                "(Landroid/content/Intent;)Landroid/app/TaskStackBuilder;",
+                &[JValue::Object(&intent_object)],
+            )?;
+
+            Ok(inner)
+        });
+        self
+    }
+
+    /// Start all the activities in the synthesized task stack.
+    ///
+    /// <https://developer.android.com/reference/android/app/TaskStackBuilder#startActivities()>
+    pub fn start_activities(self) -> crate::error::Result<()> {
+        let inner = self.inner?;
+
+        crate::error::catch(inner.env, |env| {
+            env.call_method(&inner.object, "startActivities", "()V", &[])?;
+
+            Ok(())
+        })
+    }
+}